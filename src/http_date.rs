@@ -6,7 +6,23 @@
 
 pub const GMT: &str = "GMT";
 
-#[derive(Debug, PartialEq)]
+const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+const DAY_NAMES_FULL: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct HttpDate {
     ///  IMF-fixdate  = day-name "," SP date1 SP time-of-day SP GMT
     ///  ; fixed length/zone/capitalization subset of the format
@@ -43,9 +59,239 @@ pub struct HttpDate {
 }
 
 impl HttpDate {
+    /// Parses a `Date`/`Last-Modified`-style HTTP date.
+    ///
+    /// Accepts all three formats defined by
+    /// [RFC9110 §5.6.7](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.7):
+    /// IMF-fixdate, the obsolete RFC 850 format, and the obsolete ANSI C
+    /// `asctime()` format. The result is always normalized to the
+    /// IMF-fixdate field shapes (3-letter day name, `DD Mon YYYY` date).
     pub fn from_str(v: &str) -> Option<HttpDate> {
-        None
+        let v = v.trim();
+        match v.split_once(',') {
+            Some((day_name, rest)) if rest.trim_start().contains('-') => {
+                HttpDate::from_rfc850(day_name, rest.trim())
+            }
+            Some((day_name, rest)) => HttpDate::from_imf_fixdate(day_name, rest.trim()),
+            None => HttpDate::from_asctime(v),
+        }
+    }
+
+    /// IMF-fixdate: `Sun, 06 Nov 1994 08:49:37 GMT`
+    fn from_imf_fixdate(day_name: &str, rest: &str) -> Option<HttpDate> {
+        let day_name = normalize_day_name(day_name)?;
+
+        let fields = rest.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 || fields[4] != GMT {
+            return None;
+        }
+        let (day_str, month, year_str, time_of_day) = (fields[0], fields[1], fields[2], fields[3]);
+
+        let day = parse_day(day_str)?;
+        let month = normalize_month(month)?;
+        let year = year_str.parse::<u16>().ok()?;
+        let (hour, minute, second) = parse_time_of_day(time_of_day)?;
+
+        Some(HttpDate {
+            day_name,
+            date: format!("{:02} {} {}", day, month, year),
+            day,
+            month,
+            year,
+            time_of_day: time_of_day.to_string(),
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Obsolete RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+    fn from_rfc850(day_name: &str, rest: &str) -> Option<HttpDate> {
+        let day_name = normalize_day_name(day_name)?;
+
+        let fields = rest.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 3 || fields[2] != GMT {
+            return None;
+        }
+        let (date, time_of_day) = (fields[0], fields[1]);
+
+        let date_parts = date.split('-').collect::<Vec<_>>();
+        if date_parts.len() != 3 {
+            return None;
+        }
+        let (day_str, month, year_str) = (date_parts[0], date_parts[1], date_parts[2]);
+
+        let day = parse_day(day_str)?;
+        let month = normalize_month(month)?;
+        let short_year = year_str.parse::<u16>().ok()?;
+        if short_year > 99 {
+            return None;
+        }
+        // RFC9110 §5.6.7 two-digit year, expanded with a fixed pivot:
+        // 00-69 become 2000-2069, 70-99 become 1970-1999.
+        let year = short_year + if short_year < 70 { 2000 } else { 1900 };
+        let (hour, minute, second) = parse_time_of_day(time_of_day)?;
+
+        Some(HttpDate {
+            day_name,
+            date: format!("{:02} {} {}", day, month, year),
+            day,
+            month,
+            year,
+            time_of_day: time_of_day.to_string(),
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Obsolete ANSI C asctime(): `Sun Nov  6 08:49:37 1994`
+    fn from_asctime(v: &str) -> Option<HttpDate> {
+        // the day field is space-padded, e.g. "Nov  6"; collapse runs of
+        // whitespace so the empty token disappears.
+        let fields = v.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 5 {
+            return None;
+        }
+        let (day_name, month, day_str, time_of_day, year_str) =
+            (fields[0], fields[1], fields[2], fields[3], fields[4]);
+
+        let day_name = normalize_day_name(day_name)?;
+        let month = normalize_month(month)?;
+        let day = parse_day(day_str)?;
+        let year = year_str.parse::<u16>().ok()?;
+        let (hour, minute, second) = parse_time_of_day(time_of_day)?;
+
+        Some(HttpDate {
+            day_name,
+            date: format!("{:02} {} {}", day, month, year),
+            day,
+            month,
+            year,
+            time_of_day: time_of_day.to_string(),
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Renders the canonical IMF-fixdate form used for `Last-Modified`/`Date` headers.
+    ///
+    /// `day-name "," SP date1 SP time-of-day SP GMT`
+    pub fn to_imf_fixdate(&self) -> String {
+        format!("{}, {} {} {}", self.day_name, self.date, self.time_of_day, GMT)
+    }
+
+    /// Converts this date to a Unix timestamp (seconds since 1970-01-01T00:00:00Z).
+    ///
+    /// Uses Howard Hinnant's civil-date algorithm:
+    /// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+    pub fn to_unix_timestamp(&self) -> i64 {
+        let month = (MONTH_NAMES.iter().position(|&m| m == self.month).unwrap() + 1) as i64;
+        let day = self.day as i64;
+        let year = self.year as i64;
+
+        let y = year - i64::from(month <= 2);
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let mp = (month + 9) % 12;
+        let doy = (153 * mp + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146097 + doe - 719468;
+
+        days * 86400 + self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64
     }
+
+    /// Builds an `HttpDate` from a Unix timestamp (seconds since 1970-01-01T00:00:00Z).
+    ///
+    /// Inverse of [`HttpDate::to_unix_timestamp`], using Howard Hinnant's
+    /// civil-date algorithm: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    pub fn from_unix_timestamp(timestamp: i64) -> HttpDate {
+        let days = timestamp.div_euclid(86400);
+        let secs_of_day = timestamp.rem_euclid(86400);
+        let hour = (secs_of_day / 3600) as u8;
+        let minute = (secs_of_day % 3600 / 60) as u8;
+        let second = (secs_of_day % 60) as u8;
+
+        let z = days + 719468;
+        let era = if z >= 0 { z } else { z - 146096 } / 146097;
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let y = yoe + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = (y + i64::from(month <= 2)) as u16;
+
+        let month = MONTH_NAMES[(month - 1) as usize].to_string();
+        // 1970-01-01 (day 0) was a Thursday, index 3 in `DAY_NAMES`.
+        let day_name = DAY_NAMES[(days + 3).rem_euclid(7) as usize].to_string();
+        let time_of_day = format!("{:02}:{:02}:{:02}", hour, minute, second);
+        let date = format!("{:02} {} {}", day, month, year);
+
+        HttpDate {
+            day_name,
+            date,
+            day,
+            month,
+            year,
+            time_of_day,
+            hour,
+            minute,
+            second,
+        }
+    }
+}
+
+impl PartialOrd for HttpDate {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HttpDate {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.to_unix_timestamp().cmp(&other.to_unix_timestamp())
+    }
+}
+
+fn normalize_day_name(day_name: &str) -> Option<String> {
+    if let Some(index) = DAY_NAMES.iter().position(|&d| d == day_name) {
+        return Some(DAY_NAMES[index].to_string());
+    }
+    let index = DAY_NAMES_FULL.iter().position(|&d| d == day_name)?;
+    Some(DAY_NAMES[index].to_string())
+}
+
+fn normalize_month(month: &str) -> Option<String> {
+    let index = MONTH_NAMES.iter().position(|&m| m == month)?;
+    Some(MONTH_NAMES[index].to_string())
+}
+
+fn parse_day(day_str: &str) -> Option<u8> {
+    let day = day_str.parse::<u8>().ok()?;
+    if day == 0 || day > 31 {
+        return None;
+    }
+    Some(day)
+}
+
+fn parse_time_of_day(time_of_day: &str) -> Option<(u8, u8, u8)> {
+    let parts = time_of_day.split(':').collect::<Vec<_>>();
+    if parts.len() != 3 {
+        return None;
+    }
+    let hour = parts[0].parse::<u8>().ok()?;
+    let minute = parts[1].parse::<u8>().ok()?;
+    let second = parts[2].parse::<u8>().ok()?;
+
+    if hour > 23 || minute > 59 || second > 60 {
+        // second <= 60 to allow for leap seconds
+        return None;
+    }
+
+    Some((hour, minute, second))
 }
 
 #[cfg(test)]
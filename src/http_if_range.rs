@@ -7,3 +7,119 @@ pub enum HttpIfRange {
     HttpETag(String)
 }
 
+impl HttpIfRange {
+    /// Returns a parsed value of the `If-Range` header
+    ///
+    /// The header carries either an ETag (a quoted string, optionally
+    /// prefixed with `W/` for a weak validator) or an HTTP-date.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - a `&str` input to parse, a value part of the `If-Range` header
+    pub fn from_header(value: &str) -> Option<HttpIfRange> {
+        let value = value.trim();
+        if value.starts_with('"') || value.starts_with("W/\"") {
+            Some(HttpIfRange::HttpETag(value.to_string()))
+        } else {
+            HttpDate::from_str(value).map(HttpIfRange::HttpDate)
+        }
+    }
+
+    /// Returns a `bool` indicating whether the range should be served
+    ///
+    /// Per [RFC9110 §13.1.5](https://www.rfc-editor.org/rfc/rfc9110.html#section-13.1.5),
+    /// an `If-Range` ETag is compared using the **strong** comparison
+    /// function: a weak validator (`W/"..."`) never matches, on either side.
+    /// An `If-Range` date matches only if the resource has not been modified
+    /// after it.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_etag` - the resource's current `ETag` header value, if any
+    /// * `last_modified` - the resource's current `Last-Modified` value, if any
+    pub fn should_apply_range(
+        &self,
+        current_etag: Option<&str>,
+        last_modified: Option<&HttpDate>,
+    ) -> bool {
+        match self {
+            HttpIfRange::HttpETag(etag) => {
+                if etag.starts_with("W/") {
+                    return false;
+                }
+                match current_etag {
+                    Some(current) if !current.starts_with("W/") => etag == current,
+                    _ => false,
+                }
+            }
+            HttpIfRange::HttpDate(date) => match last_modified {
+                Some(last_modified) => last_modified <= date,
+                None => false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_header_etag() {
+        let if_range = HttpIfRange::from_header("\"abc123\"").unwrap();
+        assert!(matches!(if_range, HttpIfRange::HttpETag(ref etag) if etag == "\"abc123\""));
+    }
+
+    #[test]
+    fn from_header_weak_etag() {
+        let if_range = HttpIfRange::from_header("W/\"abc123\"").unwrap();
+        assert!(matches!(if_range, HttpIfRange::HttpETag(ref etag) if etag == "W/\"abc123\""));
+    }
+
+    #[test]
+    fn from_header_date() {
+        let if_range = HttpIfRange::from_header("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        assert!(matches!(if_range, HttpIfRange::HttpDate(_)));
+    }
+
+    #[test]
+    fn strong_etag_match_applies_range() {
+        let if_range = HttpIfRange::HttpETag("\"abc123\"".to_string());
+        assert!(if_range.should_apply_range(Some("\"abc123\""), None));
+    }
+
+    #[test]
+    fn strong_etag_mismatch_rejects_range() {
+        let if_range = HttpIfRange::HttpETag("\"abc123\"".to_string());
+        assert!(!if_range.should_apply_range(Some("\"other\""), None));
+    }
+
+    #[test]
+    fn weak_if_range_etag_never_matches() {
+        let if_range = HttpIfRange::HttpETag("W/\"abc123\"".to_string());
+        assert!(!if_range.should_apply_range(Some("\"abc123\""), None));
+    }
+
+    #[test]
+    fn weak_current_etag_never_matches() {
+        let if_range = HttpIfRange::HttpETag("\"abc123\"".to_string());
+        assert!(!if_range.should_apply_range(Some("W/\"abc123\""), None));
+    }
+
+    #[test]
+    fn date_not_modified_since_applies_range() {
+        let if_range_date = HttpDate::from_str("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        let last_modified = HttpDate::from_str("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        let if_range = HttpIfRange::HttpDate(if_range_date);
+        assert!(if_range.should_apply_range(None, Some(&last_modified)));
+    }
+
+    #[test]
+    fn date_modified_since_rejects_range() {
+        let if_range_date = HttpDate::from_str("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        let last_modified = HttpDate::from_str("Mon, 07 Nov 1994 08:49:37 GMT").unwrap();
+        let if_range = HttpIfRange::HttpDate(if_range_date);
+        assert!(!if_range.should_apply_range(None, Some(&last_modified)));
+    }
+}
+
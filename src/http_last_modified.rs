@@ -4,4 +4,20 @@ use crate::http_date::HttpDate;
 
 pub struct HttpLastModified {
     pub last_modified: HttpDate
+}
+
+impl HttpLastModified {
+    /// `If-Modified-Since`: true if the resource has changed after `since`.
+    ///
+    /// [RFC9110 §13.1.3](https://www.rfc-editor.org/rfc/rfc9110#section-13.1.3)
+    pub fn is_modified_since(&self, since: &HttpDate) -> bool {
+        self.last_modified > *since
+    }
+
+    /// `If-Unmodified-Since`: true if the resource has not changed after `since`.
+    ///
+    /// [RFC9110 §13.1.4](https://www.rfc-editor.org/rfc/rfc9110#section-13.1.4)
+    pub fn is_unmodified_since(&self, since: &HttpDate) -> bool {
+        !self.is_modified_since(since)
+    }
 }
\ No newline at end of file
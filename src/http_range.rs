@@ -33,119 +33,272 @@ pub enum CompleteLength {
     Unknown,
 }
 
-impl HttpRange {
-    /// Returns a parsed value of `CONTENT_RANGE` header
+#[derive(Debug, PartialEq)]
+/// Errors returned by [`HttpRange::parse_range_request`]
+pub enum RangeError {
+    /// the range unit is not `bytes`
+    InvalidUnit,
+
+    /// the header value does not match the `Range` request grammar
+    InvalidSyntax,
+
+    /// a numeric field could not be parsed, either non-digit or too large for a `u64`
+    NumberOverflow,
+
+    /// none of the requested ranges fall within the content length
+    ///
+    /// Reference: [416 Range Not Satisfiable](https://datatracker.ietf.org/doc/html/rfc7233#section-4.4)
+    Unsatisfiable,
+}
+
+impl std::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::InvalidUnit => write!(f, "range unit is not '{}'", RANGE_UNIT),
+            RangeError::InvalidSyntax => write!(f, "invalid Range header syntax"),
+            RangeError::NumberOverflow => write!(f, "range value is not a valid number"),
+            RangeError::Unsatisfiable => write!(f, "range not satisfiable"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+#[derive(Debug, PartialEq)]
+/// A parsed `Content-Range` header, covering range units beyond `bytes`
+///
+/// [RFC9110 §14.4](https://www.rfc-editor.org/rfc/rfc9110.html#section-14.4) and the
+/// [Range Unit Registry](https://www.iana.org/assignments/http-range-units) allow
+/// range units other than `bytes` (e.g. `seconds`); those are kept verbatim so
+/// they round-trip through [`HttpContentRange::to_header`] instead of being dropped.
+pub enum HttpContentRange {
+    /// A `bytes` range, fully parsed into [`HttpRange`]
+    Range(HttpRange),
+
+    /// A range using a unit this crate does not model, kept verbatim
+    Unregistered {
+        /// the range unit, e.g. `seconds`
+        unit: String,
+        /// everything following the unit, unparsed
+        resp: String,
+    },
+}
+
+impl HttpContentRange {
+    /// Returns a parsed value of the response `Content-Range` header for any range unit
     ///
     /// # Arguments
     ///
-    /// * `content_range` - a `&str` input to parse, a value part of `CONTENT_RANGE` header
-    /// * `content_length` - a `u64` length of existing content, in bytes
-    pub fn from_header(content_range: &str, content_length: u64) -> Option<HttpRange> {
+    /// * `content_range` - a `&str` input to parse, a value part of the `Content-Range` header
+    pub fn from_header(content_range: &str) -> Option<HttpContentRange> {
         if content_range.is_empty() {
             return None;
         }
 
-        let parts = content_range
-            .split("=")
-            .map(|p| p.trim())
-            .collect::<Vec<_>>();
-        if parts.is_empty() {
+        let parts = content_range.splitn(2, ' ').collect::<Vec<_>>();
+        if parts.len() != 2 {
             return None;
         }
 
-        if parts[0] != RANGE_UNIT {
-            return None;
+        if parts[0] == RANGE_UNIT {
+            HttpRange::parse_content_range(content_range).map(HttpContentRange::Range)
+        } else {
+            Some(HttpContentRange::Unregistered {
+                unit: parts[0].to_string(),
+                resp: parts[1].to_string(),
+            })
         }
+    }
 
-        if parts.len() != 2 {
-            return None;
+    /// Returns a `Content-Range` header value
+    pub fn to_header(&self) -> String {
+        match self {
+            HttpContentRange::Range(range) => range.to_header(),
+            HttpContentRange::Unregistered { unit, resp } => format!("{} {}", unit, resp),
         }
+    }
+}
 
-        let params = parts[1].split("/").map(|p| p.trim()).collect::<Vec<_>>();
-        if params.is_empty() {
-            return None;
+impl HttpRange {
+    /// Returns a parsed value of the request `Range` header
+    ///
+    /// Syntax: `bytes=first-last[,first-last...]`, with support for
+    /// suffix-ranges (`-500`) and open-ended ranges (`9500-`). Overlapping
+    /// and continuous ranges are merged per
+    /// [RFC7233 §4.3](https://datatracker.ietf.org/doc/html/rfc7233#section-4.3).
+    ///
+    /// These values come directly from untrusted client input, so every
+    /// numeric field is parsed fallibly and a suffix range larger than the
+    /// content is clamped rather than underflowing.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - a `&str` input to parse, a value part of the `Range` header
+    /// * `content_length` - a `u64` length of existing content, in bytes
+    pub fn parse_range_request(range: &str, content_length: u64) -> Result<HttpRange, RangeError> {
+        if range.is_empty() {
+            return Err(RangeError::InvalidSyntax);
         }
 
-        if params.len() > 2 {
-            return None;
+        let parts = range.split("=").map(|p| p.trim()).collect::<Vec<_>>();
+        if parts.len() != 2 {
+            return Err(RangeError::InvalidSyntax);
+        }
+
+        if parts[0] != RANGE_UNIT {
+            return Err(RangeError::InvalidUnit);
         }
 
-        let range_params = params[0].split(",");
-        let length_param = if params.len() == 2 { params[1] } else { "" };
+        let range_params = parts[1].split(",");
 
         let mut ranges = Vec::<Range<u64>>::new();
         for range_param in range_params {
             let values = range_param.split("-").map(|v| v.trim()).collect::<Vec<_>>();
             if values.len() != 2 {
-                return None;
+                return Err(RangeError::InvalidSyntax);
             }
-            let mut range = 0..content_length - 1;
             let start = values[0];
             let end = values[1];
 
-            if !start.is_empty() && !end.is_empty() {
-                range.start = start.parse::<u64>().unwrap();
-                range.end = end.parse::<u64>().unwrap();
-            }
-            if start.is_empty() && !end.is_empty() {
-                let count = end.parse::<u64>().unwrap();
-                range.start = content_length - count;
-            }
-
-            if !start.is_empty() && end.is_empty() {
-                range.start = start.parse::<u64>().unwrap();
-            }
+            let range = if !start.is_empty() && !end.is_empty() {
+                let start = start.parse::<u64>().map_err(|_| RangeError::NumberOverflow)?;
+                let end = end.parse::<u64>().map_err(|_| RangeError::NumberOverflow)?;
+                if end < start {
+                    return Err(RangeError::InvalidSyntax);
+                }
+                // a start at or past the content is not satisfiable on its
+                // own and must be dropped rather than retained as a
+                // reversed (clamped-end < start) range.
+                if start >= content_length {
+                    continue;
+                }
+                start..end.min(content_length.saturating_sub(1))
+            } else if start.is_empty() && !end.is_empty() {
+                let count = end.parse::<u64>().map_err(|_| RangeError::NumberOverflow)?;
+                content_length.saturating_sub(count)..content_length.saturating_sub(1)
+            } else if !start.is_empty() && end.is_empty() {
+                let start = start.parse::<u64>().map_err(|_| RangeError::NumberOverflow)?;
+                start..content_length.saturating_sub(1)
+            } else {
+                return Err(RangeError::InvalidSyntax);
+            };
 
             ranges.push(range);
         }
 
-        // processing for combined ranges
-        // https://datatracker.ietf.org/doc/html/rfc7233#section-4.3
-        ranges.sort_by(|a, b| a.start.cmp(&b.start));
-        let ranges_count = ranges.len();
-        if ranges_count > 1 {
-            // merging continuous and overlapping ranges
-            let mut retain = vec![true; ranges_count];
-            let mut range_last = ranges[0].clone();
-            for (index, range) in ranges.iter_mut().enumerate() {
-                if index != 0 && (range_last.end + 1) >= range.start {
-                    range.start = range_last.start;
-                    retain[index - 1] = false;
-                }
-                range_last = range.clone();
-            }
+        merge_ranges(&mut ranges);
 
-            // cleaning-up merged ranges
-            let mut index = 0;
-            ranges.retain(|_| {
-                let keep = retain[index];
-                index += 1;
-                keep
-            });
+        if !ranges
+            .iter()
+            .any(|r| HttpRange::range_satisfiable(r, content_length))
+        {
+            return Err(RangeError::Unsatisfiable);
+        }
+
+        Ok(HttpRange {
+            ranges,
+            complete_length: None,
+        })
+    }
+
+    /// Returns a parsed value of the request `Range` header, or `None` on any parse failure
+    ///
+    /// Thin `Option`-returning wrapper over [`HttpRange::parse_range_request`]
+    /// kept for callers that only need to know whether parsing succeeded.
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - a `&str` input to parse, a value part of the `Range` header
+    /// * `content_length` - a `u64` length of existing content, in bytes
+    pub fn parse_range_request_opt(range: &str, content_length: u64) -> Option<HttpRange> {
+        HttpRange::parse_range_request(range, content_length).ok()
+    }
+
+    /// Returns a parsed value of the response `Content-Range` header
+    ///
+    /// Syntax: `bytes first-last/complete-length`, with `complete-length`
+    /// being `*` when the total size of the representation is unknown.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_range` - a `&str` input to parse, a value part of the `Content-Range` header
+    pub fn parse_content_range(content_range: &str) -> Option<HttpRange> {
+        if content_range.is_empty() {
+            return None;
         }
 
+        let parts = content_range.splitn(2, ' ').collect::<Vec<_>>();
+        if parts.len() != 2 {
+            return None;
+        }
+
+        if parts[0] != RANGE_UNIT {
+            return None;
+        }
+
+        let segments = parts[1].splitn(2, '/').collect::<Vec<_>>();
+        if segments.len() != 2 {
+            return None;
+        }
+
+        // range-resp can be '*' when the range itself is unsatisfied/unknown,
+        // e.g. a 416 response carrying only the complete length.
+        let ranges = if segments[0] == "*" {
+            Vec::new()
+        } else {
+            let values = segments[0]
+                .split("-")
+                .map(|v| v.trim())
+                .collect::<Vec<_>>();
+            if values.len() != 2 {
+                return None;
+            }
+            let start = values[0].parse::<u64>().ok()?;
+            let end = values[1].parse::<u64>().ok()?;
+            vec![start..end]
+        };
+
+        let length_param = segments[1];
         let complete_length = match length_param {
-            "" => None,
             "*" => Some(CompleteLength::Unknown),
             _ => Some(CompleteLength::Representation(
-                length_param.parse::<u64>().unwrap(),
+                length_param.parse::<u64>().ok()?,
             )),
         };
 
-        let http_range = HttpRange {
+        Some(HttpRange {
             ranges,
             complete_length,
+        })
+    }
+
+    /// Returns a `Content-Range` header value
+    pub fn to_header(&self) -> String {
+        if self.ranges.is_empty() && self.complete_length.is_none() {
+            return "".to_string();
+        }
+
+        let ranges = if self.ranges.is_empty() {
+            "*".to_string()
+        } else {
+            self.ranges
+                .iter()
+                .map(|r| format!("{}-{}", r.start, r.end))
+                .collect::<Vec<_>>()
+                .join(",")
         };
 
-        Some(http_range)
+        match &self.complete_length {
+            Some(CompleteLength::Representation(content_length)) => {
+                format!("{} {}/{}", RANGE_UNIT, ranges, content_length)
+            }
+            Some(CompleteLength::Unknown) => format!("{} {}/*", RANGE_UNIT, ranges),
+            None => format!("{} {}", RANGE_UNIT, ranges),
+        }
     }
 
-    /// Returns a `CONTENT_RANGE` header value
-    ///
-    /// # Arguments
-    ///
-    /// * `http_range` - a reference to `HttpRange`
-    pub fn to_header(&self) -> String {
+    /// Returns a request `Range` header value
+    pub fn to_range_request_header(&self) -> String {
         if self.ranges.is_empty() {
             return "".to_string();
         }
@@ -157,13 +310,7 @@ impl HttpRange {
             .collect::<Vec<_>>()
             .join(",");
 
-        match &self.complete_length {
-            Some(CompleteLength::Representation(content_length)) => {
-                format!("{}={}/{}", RANGE_UNIT, ranges, content_length)
-            }
-            Some(CompleteLength::Unknown) => format!("{}={}/*", RANGE_UNIT, ranges),
-            None => format!("{}={}", RANGE_UNIT, ranges),
-        }
+        format!("{}={}", RANGE_UNIT, ranges)
     }
 
     /// Returns a `bool` indicating if none of the ranges in `HttpRange` are satisfiable within `content_length`
@@ -206,6 +353,191 @@ impl HttpRange {
     pub fn range_satisfiable(range: &Range<u64>, content_length: u64) -> bool {
         range.start < content_length
     }
+
+    /// Returns the response shape a server should use for these ranges
+    ///
+    /// [RFC9110 §14](https://www.rfc-editor.org/rfc/rfc9110.html#section-14):
+    /// a single satisfiable range is served as a 206 with a single
+    /// `Content-Range`, several satisfiable ranges require a 206
+    /// `multipart/byteranges` body, and none satisfiable is a 416.
+    ///
+    /// # Arguments
+    ///
+    /// * `content_length` - a `u64` length of existing content, in bytes
+    pub fn response_shape(&self, content_length: u64) -> RangeResponseShape {
+        let satisfiable = self
+            .ranges
+            .iter()
+            .filter(|r| HttpRange::range_satisfiable(r, content_length))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        match satisfiable.len() {
+            0 => RangeResponseShape::Unsatisfiable,
+            1 => RangeResponseShape::Single(satisfiable.into_iter().next().unwrap()),
+            _ => RangeResponseShape::Multipart(satisfiable),
+        }
+    }
+
+    /// Returns an iterator over the `multipart/byteranges` parts for these ranges
+    ///
+    /// Each yielded [`MultipartBytePart::Part`] carries the part's header
+    /// text (the `--boundary` line, `Content-Type`, `Content-Range` and the
+    /// trailing blank line) together with the byte `range` the caller must
+    /// copy from the representation into the part body; `range` is
+    /// half-open, matching the number of bytes the `Content-Range` line
+    /// announces. The final item is the closing `--boundary--` delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary` - the multipart boundary, without the leading `--`
+    /// * `content_type` - the representation's `Content-Type`, repeated per part
+    /// * `complete_length` - a `u64` length of the complete representation, in bytes
+    pub fn multipart_parts<'a>(
+        &'a self,
+        boundary: &'a str,
+        content_type: &'a str,
+        complete_length: u64,
+    ) -> MultipartByterangesIter<'a> {
+        MultipartByterangesIter {
+            ranges: self.ranges.iter(),
+            boundary,
+            content_type,
+            complete_length,
+            done: false,
+        }
+    }
+
+    /// Returns the `multipart/byteranges` body scaffolding for these ranges
+    ///
+    /// Assembles the parts from [`HttpRange::multipart_parts`] into a single
+    /// `String`, with each part body replaced by a `<start-end>` placeholder
+    /// since this crate does not carry the represented bytes. Streaming
+    /// servers should use [`HttpRange::multipart_parts`] directly and copy
+    /// the real bytes for each yielded range instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary` - the multipart boundary, without the leading `--`
+    /// * `content_type` - the representation's `Content-Type`, repeated per part
+    /// * `complete_length` - a `u64` length of the complete representation, in bytes
+    pub fn to_multipart_byteranges(
+        &self,
+        boundary: &str,
+        content_type: &str,
+        complete_length: u64,
+    ) -> String {
+        let mut body = String::new();
+        for part in self.multipart_parts(boundary, content_type, complete_length) {
+            match part {
+                MultipartBytePart::Part { header, range } => {
+                    body.push_str(&header);
+                    body.push_str(&format!("<{}-{}>\r\n", range.start, range.end - 1));
+                }
+                MultipartBytePart::Closing(closing) => body.push_str(&closing),
+            }
+        }
+        body
+    }
+}
+
+/// The response shape a server should use for a set of parsed ranges
+///
+/// Reference: [RFC9110 §14](https://www.rfc-editor.org/rfc/rfc9110.html#section-14)
+#[derive(Debug, PartialEq)]
+pub enum RangeResponseShape {
+    /// Serve a 206 with a single `Content-Range` header
+    Single(Range<u64>),
+
+    /// Serve a 206 with a `multipart/byteranges` body
+    Multipart(Vec<Range<u64>>),
+
+    /// Serve a 416 Range Not Satisfiable
+    Unsatisfiable,
+}
+
+/// One part of a `multipart/byteranges` response body, yielded by [`MultipartByterangesIter`]
+#[derive(Debug, PartialEq)]
+pub enum MultipartBytePart {
+    /// A part's header text plus the byte range the caller should copy into its body
+    ///
+    /// `range` is half-open (Rust slice-indexing convention, `data[range]`),
+    /// unlike the inclusive `first-last` positions in `header`'s
+    /// `Content-Range` line: a part announcing `bytes 0-499/10000` yields
+    /// `range: 0..500`, covering exactly the 500 announced bytes.
+    Part { header: String, range: Range<u64> },
+
+    /// The closing `--boundary--` delimiter
+    Closing(String),
+}
+
+/// Iterator over the parts of a `multipart/byteranges` response body
+///
+/// Returned by [`HttpRange::multipart_parts`].
+pub struct MultipartByterangesIter<'a> {
+    ranges: std::slice::Iter<'a, Range<u64>>,
+    boundary: &'a str,
+    content_type: &'a str,
+    complete_length: u64,
+    done: bool,
+}
+
+impl<'a> Iterator for MultipartByterangesIter<'a> {
+    type Item = MultipartBytePart;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(range) = self.ranges.next() {
+            let header = format!(
+                "--{}\r\nContent-Type: {}\r\nContent-Range: {} {}-{}/{}\r\n\r\n",
+                self.boundary,
+                self.content_type,
+                RANGE_UNIT,
+                range.start,
+                range.end,
+                self.complete_length
+            );
+            // header positions are inclusive (`first-last`); the yielded
+            // range is half-open, so the upper bound needs +1.
+            return Some(MultipartBytePart::Part {
+                header,
+                range: range.start..range.end.saturating_add(1),
+            });
+        }
+
+        if !self.done {
+            self.done = true;
+            return Some(MultipartBytePart::Closing(format!("--{}--\r\n", self.boundary)));
+        }
+
+        None
+    }
+}
+
+/// Sorts and merges continuous and overlapping ranges in place.
+///
+/// Reference: [RFC7233 §4.3](https://datatracker.ietf.org/doc/html/rfc7233#section-4.3)
+fn merge_ranges(ranges: &mut Vec<Range<u64>>) {
+    ranges.sort_by(|a, b| a.start.cmp(&b.start));
+    let ranges_count = ranges.len();
+    if ranges_count > 1 {
+        let mut retain = vec![true; ranges_count];
+        let mut range_last = ranges[0].clone();
+        for (index, range) in ranges.iter_mut().enumerate() {
+            if index != 0 && range_last.end.saturating_add(1) >= range.start {
+                range.start = range_last.start;
+                range.end = range.end.max(range_last.end);
+                retain[index - 1] = false;
+            }
+            range_last = range.clone();
+        }
+
+        let mut index = 0;
+        ranges.retain(|_| {
+            let keep = retain[index];
+            index += 1;
+            keep
+        });
+    }
 }
 
 #[cfg(test)]
@@ -222,35 +554,9 @@ mod tests {
     ///    -  The second 500 bytes (byte offsets 500-999, inclusive):
     ///         bytes=500-999
     ///
-    ///  Additional examples, assuming a representation of length 10000:
-    ///    The final 500 bytes (byte offsets 9500-9999, inclusive):
-    ///         bytes=-500
-    ///    Or:
-    ///         bytes=9500-
-    ///    -  The first and last bytes only (bytes 0 and 9999):
-    ///         bytes=0-0,-1
-    ///    -  Other valid (but not canonical) specifications of the second 500
-    ///       bytes (byte offsets 500-999, inclusive):
-    ///         bytes=500-600,601-999
-    ///         bytes=500-700,601-999
-    ///
-    ///  Additional examples
-    ///
-    ///     - The first 500 bytes:
-    ///         Content-Range: bytes 0-499/1234
-    ///
-    ///     - The second 500 bytes:
-    ///         Content-Range: bytes 500-999/1234
-    ///
-    ///     - All except for the first 500 bytes:
-    ///         Content-Range: bytes 500-1233/1234
-    ///  
-    ///     - The last 500 bytes:
-    ///         Content-Range: bytes 734-1233/1234
-
     #[test]
     fn test1() {
-        let http_range = HttpRange::from_header("bytes=0-499", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=0-499", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -260,33 +566,9 @@ mod tests {
         );
     }
 
-    #[test]
-    fn complete_length_unknown() {
-        let http_range = HttpRange::from_header("bytes=0-499/*", 10000).unwrap();
-        assert_eq!(
-            http_range,
-            HttpRange {
-                ranges: vec![0..499],
-                complete_length: Some(CompleteLength::Unknown)
-            }
-        );
-    }
-
-    #[test]
-    fn complete_length_test2() {
-        let http_range = HttpRange::from_header("bytes=0-499/8000", 10000).unwrap();
-        assert_eq!(
-            http_range,
-            HttpRange {
-                ranges: vec![0..499],
-                complete_length: Some(CompleteLength::Representation(8000))
-            }
-        );
-    }
-
     #[test]
     fn test2() {
-        let http_range = HttpRange::from_header("bytes=500-999", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=500-999", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -298,7 +580,7 @@ mod tests {
 
     #[test]
     fn test3() {
-        let http_range = HttpRange::from_header("bytes=-500", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=-500", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -310,7 +592,7 @@ mod tests {
 
     #[test]
     fn test4() {
-        let http_range = HttpRange::from_header("bytes=9500-", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=9500-", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -322,7 +604,7 @@ mod tests {
 
     #[test]
     fn test5() {
-        let http_range = HttpRange::from_header("bytes=0-0,-1", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=0-0,-1", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -335,7 +617,8 @@ mod tests {
     fn test6() {
         // https://www.rfc-editor.org/rfc/rfc9110.html#section-14.1.2
         // the first, middle, and last 1000 bytes
-        let http_range = HttpRange::from_header("bytes= 0-999, 4500-5499, -1000", 10000).unwrap();
+        let http_range =
+            HttpRange::parse_range_request("bytes= 0-999, 4500-5499, -1000", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -347,7 +630,7 @@ mod tests {
 
     #[test]
     fn combined_merge_test6() {
-        let http_range = HttpRange::from_header("bytes=500-600,601-999", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=500-600,601-999", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -359,7 +642,7 @@ mod tests {
 
     #[test]
     fn combined_merge_test7() {
-        let http_range = HttpRange::from_header("bytes=601-999,500-600", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=601-999,500-600", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -371,7 +654,7 @@ mod tests {
 
     #[test]
     fn combined_merge_test8() {
-        let http_range = HttpRange::from_header("bytes=500-700,601-999", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=500-700,601-999", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -383,7 +666,7 @@ mod tests {
 
     #[test]
     fn combined_merge_test9() {
-        let http_range = HttpRange::from_header("bytes=601-999,500-700", 10000).unwrap();
+        let http_range = HttpRange::parse_range_request("bytes=601-999,500-700", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -395,7 +678,8 @@ mod tests {
 
     #[test]
     fn combined_merge_test10() {
-        let http_range = HttpRange::from_header("bytes=300-400,400-700,601-999", 10000).unwrap();
+        let http_range =
+            HttpRange::parse_range_request("bytes=300-400,400-700,601-999", 10000).unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -405,9 +689,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn content_range_unknown_length() {
+        let http_range = HttpRange::parse_content_range("bytes 0-499/*").unwrap();
+        assert_eq!(
+            http_range,
+            HttpRange {
+                ranges: vec![0..499],
+                complete_length: Some(CompleteLength::Unknown)
+            }
+        );
+    }
+
     #[test]
     fn representation_test1() {
-        let http_range = HttpRange::from_header("bytes=0-499/1234", 10000).unwrap();
+        let http_range = HttpRange::parse_content_range("bytes 0-499/1234").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -419,7 +715,7 @@ mod tests {
 
     #[test]
     fn representation_test2() {
-        let http_range = HttpRange::from_header("bytes=500-999/1234", 10000).unwrap();
+        let http_range = HttpRange::parse_content_range("bytes 500-999/1234").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -431,7 +727,7 @@ mod tests {
 
     #[test]
     fn representation_test3() {
-        let http_range = HttpRange::from_header("bytes=500-1233/1234", 10000).unwrap();
+        let http_range = HttpRange::parse_content_range("bytes 500-1233/1234").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -443,7 +739,7 @@ mod tests {
 
     #[test]
     fn representation_test4() {
-        let http_range = HttpRange::from_header("bytes=734-1233/1234", 10000).unwrap();
+        let http_range = HttpRange::parse_content_range("bytes 734-1233/1234").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -455,7 +751,7 @@ mod tests {
 
     #[test]
     fn to_header_test1() {
-        let http_range = HttpRange::from_header("bytes=734-1233/1234", 1234).unwrap();
+        let http_range = HttpRange::parse_content_range("bytes 734-1233/1234").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -463,12 +759,12 @@ mod tests {
                 complete_length: Some(CompleteLength::Representation(1234))
             }
         );
-        assert_eq!(http_range.to_header(), "bytes=734-1233/1234");
+        assert_eq!(http_range.to_header(), "bytes 734-1233/1234");
     }
 
     #[test]
     fn to_header_test2() {
-        let http_range = HttpRange::from_header("bytes=734-1233/*", 1234).unwrap();
+        let http_range = HttpRange::parse_content_range("bytes 734-1233/*").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
@@ -476,20 +772,281 @@ mod tests {
                 complete_length: Some(CompleteLength::Unknown)
             }
         );
-        assert_eq!(http_range.to_header(), "bytes=734-1233/*");
+        assert_eq!(http_range.to_header(), "bytes 734-1233/*");
+    }
+
+    #[test]
+    fn to_range_request_header_test1() {
+        let http_range = HttpRange::parse_range_request("bytes=734-1233", 1234).unwrap();
+        assert_eq!(
+            http_range,
+            HttpRange {
+                ranges: vec![734..1233],
+                complete_length: None
+            }
+        );
+        assert_eq!(http_range.to_range_request_header(), "bytes=734-1233");
+    }
+
+    #[test]
+    fn to_range_request_header_test2() {
+        let http_range =
+            HttpRange::parse_range_request("bytes=500-600,601-999", 10000).unwrap();
+        assert_eq!(
+            http_range.to_range_request_header(),
+            "bytes=500-999"
+        );
     }
 
     #[test]
-    fn to_header_test3() {
-        let http_range = HttpRange::from_header("bytes=734-1233", 1234).unwrap();
+    fn unsatisfied_range() {
+        // 416 response: range unknown/unsatisfied, complete length known
+        let http_range = HttpRange::parse_content_range("bytes */1234").unwrap();
         assert_eq!(
             http_range,
             HttpRange {
+                ranges: vec![],
+                complete_length: Some(CompleteLength::Representation(1234))
+            }
+        );
+        assert_eq!(http_range.to_header(), "bytes */1234");
+    }
+
+    #[test]
+    fn content_range_non_byte_unit() {
+        let content_range = HttpContentRange::from_header("seconds 1-2").unwrap();
+        assert_eq!(
+            content_range,
+            HttpContentRange::Unregistered {
+                unit: "seconds".to_string(),
+                resp: "1-2".to_string()
+            }
+        );
+        assert_eq!(content_range.to_header(), "seconds 1-2");
+    }
+
+    #[test]
+    fn content_range_bytes_unit_delegates() {
+        let content_range = HttpContentRange::from_header("bytes 734-1233/1234").unwrap();
+        assert_eq!(
+            content_range,
+            HttpContentRange::Range(HttpRange {
                 ranges: vec![734..1233],
+                complete_length: Some(CompleteLength::Representation(1234))
+            })
+        );
+        assert_eq!(content_range.to_header(), "bytes 734-1233/1234");
+    }
+
+    #[test]
+    fn response_shape_single() {
+        let http_range = HttpRange::parse_range_request("bytes=0-499", 10000).unwrap();
+        assert_eq!(
+            http_range.response_shape(10000),
+            RangeResponseShape::Single(0..499)
+        );
+    }
+
+    #[test]
+    fn response_shape_multipart() {
+        let http_range = HttpRange::parse_range_request("bytes=0-499,9500-9999", 10000).unwrap();
+        assert_eq!(
+            http_range.response_shape(10000),
+            RangeResponseShape::Multipart(vec![0..499, 9500..9999])
+        );
+    }
+
+    #[test]
+    fn response_shape_unsatisfiable() {
+        let http_range = HttpRange {
+            ranges: vec![20000..20999],
+            complete_length: None,
+        };
+        assert_eq!(http_range.response_shape(10000), RangeResponseShape::Unsatisfiable);
+    }
+
+    #[test]
+    fn multipart_parts_yields_headers_and_closing() {
+        let http_range = HttpRange::parse_range_request("bytes=0-499,9500-9999", 10000).unwrap();
+        let parts = http_range
+            .multipart_parts("BOUNDARY", "text/plain", 10000)
+            .collect::<Vec<_>>();
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(
+            parts[0],
+            MultipartBytePart::Part {
+                header: "--BOUNDARY\r\nContent-Type: text/plain\r\nContent-Range: bytes 0-499/10000\r\n\r\n"
+                    .to_string(),
+                range: 0..500
+            }
+        );
+        assert_eq!(
+            parts[1],
+            MultipartBytePart::Part {
+                header: "--BOUNDARY\r\nContent-Type: text/plain\r\nContent-Range: bytes 9500-9999/10000\r\n\r\n"
+                    .to_string(),
+                range: 9500..10000
+            }
+        );
+        assert_eq!(
+            parts[2],
+            MultipartBytePart::Closing("--BOUNDARY--\r\n".to_string())
+        );
+    }
+
+    #[test]
+    fn multipart_part_range_len_matches_announced_content_range() {
+        // `Content-Range: bytes 0-499/10000` announces 500 bytes; the yielded
+        // (half-open) `range` must have the same length so `data[range]`
+        // copies exactly that many bytes.
+        let http_range = HttpRange::parse_range_request("bytes=0-499", 10000).unwrap();
+        let part = http_range
+            .multipart_parts("BOUNDARY", "text/plain", 10000)
+            .next()
+            .unwrap();
+        match part {
+            MultipartBytePart::Part { range, .. } => assert_eq!(range.end - range.start, 500),
+            MultipartBytePart::Closing(_) => panic!("expected a Part"),
+        }
+    }
+
+    #[test]
+    fn parse_range_request_invalid_unit() {
+        assert_eq!(
+            HttpRange::parse_range_request("seconds=0-499", 10000),
+            Err(RangeError::InvalidUnit)
+        );
+    }
+
+    #[test]
+    fn parse_range_request_invalid_syntax() {
+        assert_eq!(
+            HttpRange::parse_range_request("bytes=abc-", 10000),
+            Err(RangeError::NumberOverflow)
+        );
+        assert_eq!(
+            HttpRange::parse_range_request("not-a-range", 10000),
+            Err(RangeError::InvalidSyntax)
+        );
+    }
+
+    #[test]
+    fn parse_range_request_number_overflow_does_not_panic() {
+        assert_eq!(
+            HttpRange::parse_range_request("bytes=-99999999999999999999", 10000),
+            Err(RangeError::NumberOverflow)
+        );
+    }
+
+    #[test]
+    fn parse_range_request_oversized_suffix_clamps_instead_of_panicking() {
+        let http_range = HttpRange::parse_range_request("bytes=-999999999", 10000).unwrap();
+        assert_eq!(
+            http_range,
+            HttpRange {
+                ranges: vec![0..9999],
+                complete_length: None
+            }
+        );
+    }
+
+    #[test]
+    fn parse_range_request_end_larger_than_content_is_clamped() {
+        let http_range = HttpRange::parse_range_request("bytes=0-99999999999", 10000).unwrap();
+        assert_eq!(
+            http_range,
+            HttpRange {
+                ranges: vec![0..9999],
+                complete_length: None
+            }
+        );
+    }
+
+    #[test]
+    fn parse_range_request_end_before_start_is_invalid_syntax() {
+        assert_eq!(
+            HttpRange::parse_range_request("bytes=500-100", 10000),
+            Err(RangeError::InvalidSyntax)
+        );
+    }
+
+    #[test]
+    fn parse_range_request_oversized_end_does_not_panic() {
+        // `end` is clamped to `content_length - 1` before merging, so this
+        // must not panic even though the raw value overflows u64 bookkeeping.
+        assert!(HttpRange::parse_range_request("bytes=0-18446744073709551615,5-6", 10000).is_ok());
+    }
+
+    #[test]
+    fn merge_ranges_saturates_instead_of_overflowing() {
+        // regression test for merge_ranges() adding 1 to a range ending at
+        // u64::MAX; must saturate rather than panic with an add overflow.
+        let mut ranges = vec![0..u64::MAX, 5..6];
+        merge_ranges(&mut ranges);
+        assert_eq!(ranges, vec![0..u64::MAX]);
+    }
+
+    #[test]
+    fn merge_ranges_containment_keeps_larger_end() {
+        // a later, narrower range fully contained in an earlier one must
+        // not shrink the merged end.
+        let http_range = HttpRange::parse_range_request("bytes=0-1000,100-200", 10000).unwrap();
+        assert_eq!(
+            http_range,
+            HttpRange {
+                ranges: vec![0..1000],
+                complete_length: None
+            }
+        );
+    }
+
+    #[test]
+    fn parse_range_request_fully_out_of_bounds_is_unsatisfiable() {
+        assert_eq!(
+            HttpRange::parse_range_request("bytes=20000-20999", 10000),
+            Err(RangeError::Unsatisfiable)
+        );
+    }
+
+    #[test]
+    fn parse_range_request_drops_out_of_bounds_range_instead_of_reversing_it() {
+        // the out-of-bounds 20000-20999 spec must be dropped, not retained
+        // as a reversed (start > end) range alongside the valid one.
+        let http_range =
+            HttpRange::parse_range_request("bytes=20000-20999,0-10", 10000).unwrap();
+        assert_eq!(
+            http_range,
+            HttpRange {
+                ranges: vec![0..10],
                 complete_length: None
             }
         );
-        assert_eq!(http_range.to_header(), "bytes=734-1233");
+        for r in &http_range.ranges {
+            assert!(r.start <= r.end);
+        }
     }
 
+    #[test]
+    fn parse_range_request_opt_wraps_result() {
+        assert_eq!(
+            HttpRange::parse_range_request_opt("bytes=0-499", 10000),
+            Some(HttpRange {
+                ranges: vec![0..499],
+                complete_length: None
+            })
+        );
+        assert_eq!(HttpRange::parse_range_request_opt("bytes=abc-", 10000), None);
+    }
+
+    #[test]
+    fn to_multipart_byteranges_assembles_scaffold() {
+        let http_range = HttpRange::parse_range_request("bytes=0-499", 10000).unwrap();
+        let scaffold = http_range.to_multipart_byteranges("BOUNDARY", "text/plain", 10000);
+        assert_eq!(
+            scaffold,
+            "--BOUNDARY\r\nContent-Type: text/plain\r\nContent-Range: bytes 0-499/10000\r\n\r\n<0-499>\r\n--BOUNDARY--\r\n"
+            // (placeholder keeps the inclusive `start-end` form used in headers)
+        );
+    }
 }